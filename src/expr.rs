@@ -0,0 +1,293 @@
+//! A small expression language layered on top of [`Token`](crate::Token), parsed with
+//! precedence climbing instead of the usual nested grammar of per-precedence-level
+//! parser functions.
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::map,
+    sequence::preceded,
+};
+
+use crate::{parse_float, parse_string, ws, Token};
+
+/// A prefix operator applicable to a single operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    /// Arithmetic negation, `-x`.
+    Neg,
+    /// Logical negation, `!x`.
+    Not,
+}
+
+/// An infix operator applicable to two operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `%`
+    Rem,
+    /// `**`
+    Pow,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `&&`
+    And,
+    /// `||`
+    Or,
+}
+
+/// Whether repeated uses of an operator at the same precedence group to the left or
+/// the right, e.g. `1 - 2 - 3` is `(1 - 2) - 3` (left) but `2 ** 3 ** 2` is
+/// `2 ** (3 ** 2)` (right).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    /// Operators group to the left: `a op b op c` == `(a op b) op c`.
+    Left,
+    /// Operators group to the right: `a op b op c` == `a op (b op c)`.
+    Right,
+}
+
+/// An expression AST node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// A literal JSON value, currently a number or string (see [`parse_primary`]).
+    Const(Token),
+    /// A prefix operator applied to an operand.
+    UnaryOp(UnaryOp, Box<Expr>),
+    /// An infix operator applied to a left and right operand.
+    BinaryOp(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+/// Look up an operator's precedence (higher binds tighter) and associativity. This is
+/// the single source of truth [`parse_binary_op`] and the precedence-climbing loop in
+/// [`parse_binary_expr`] both consult.
+fn operator_info(op: &str) -> (BinaryOp, u8, Associativity) {
+    match op {
+        "**" => (BinaryOp::Pow, 6, Associativity::Right),
+        "*" => (BinaryOp::Mul, 5, Associativity::Left),
+        "/" => (BinaryOp::Div, 5, Associativity::Left),
+        "%" => (BinaryOp::Rem, 5, Associativity::Left),
+        "+" => (BinaryOp::Add, 4, Associativity::Left),
+        "-" => (BinaryOp::Sub, 4, Associativity::Left),
+        "<=" => (BinaryOp::Le, 3, Associativity::Left),
+        ">=" => (BinaryOp::Ge, 3, Associativity::Left),
+        "<" => (BinaryOp::Lt, 3, Associativity::Left),
+        ">" => (BinaryOp::Gt, 3, Associativity::Left),
+        "==" => (BinaryOp::Eq, 2, Associativity::Left),
+        "!=" => (BinaryOp::Ne, 2, Associativity::Left),
+        "&&" => (BinaryOp::And, 1, Associativity::Left),
+        "||" => (BinaryOp::Or, 0, Associativity::Left),
+        _ => unreachable!("parse_binary_op only recognizes tags present in this table"),
+    }
+}
+
+/// Parse one infix operator, longest-match first so e.g. `<=` isn't mistaken for `<`.
+fn parse_binary_op(input: &str) -> nom::IResult<&str, (BinaryOp, u8, Associativity)> {
+    map(
+        alt((
+            tag("**"),
+            tag("<="),
+            tag(">="),
+            tag("=="),
+            tag("!="),
+            tag("&&"),
+            tag("||"),
+            tag("+"),
+            tag("-"),
+            tag("*"),
+            tag("/"),
+            tag("%"),
+            tag("<"),
+            tag(">"),
+        )),
+        operator_info,
+    )(input)
+}
+
+/// Parse a number or string literal as a [`Expr::Const`].
+fn parse_primary(input: &str) -> nom::IResult<&str, Expr> {
+    map(alt((parse_float, parse_string)), Expr::Const)(input)
+}
+
+/// Parse an optional prefix `-`/`!` applied to a primary or another unary expression.
+fn parse_unary(input: &str) -> nom::IResult<&str, Expr> {
+    alt((
+        map(preceded(ws(char('-')), parse_unary), |expr| {
+            Expr::UnaryOp(UnaryOp::Neg, Box::new(expr))
+        }),
+        map(preceded(ws(char('!')), parse_unary), |expr| {
+            Expr::UnaryOp(UnaryOp::Not, Box::new(expr))
+        }),
+        parse_primary,
+    ))(input)
+}
+
+/// Parse a binary expression via precedence climbing: parse a primary, then while the
+/// next operator binds at least as tightly as `min_prec`, consume it and recursively
+/// parse the right operand with a raised minimum precedence (one higher than the
+/// operator's own, for left-associative operators, so equal-precedence operators to
+/// the right don't get folded into this one).
+fn parse_binary_expr(input: &str, min_prec: u8) -> nom::IResult<&str, Expr> {
+    let (mut input, mut lhs) = parse_unary(input)?;
+
+    loop {
+        match ws(parse_binary_op)(input) {
+            Ok((next_input, (op, prec, assoc))) if prec >= min_prec => {
+                let next_min_prec = match assoc {
+                    Associativity::Left => prec + 1,
+                    Associativity::Right => prec,
+                };
+                let (next_input, rhs) = parse_binary_expr(next_input, next_min_prec)?;
+                lhs = Expr::BinaryOp(op, Box::new(lhs), Box::new(rhs));
+                input = next_input;
+            }
+            _ => break,
+        }
+    }
+
+    Ok((input, lhs))
+}
+
+/// Parse a full expression.
+///
+/// ## Example:
+/// ```
+/// # use zjson::*;
+/// let (_, expr) = parse_expr("1 + 2 * 3").unwrap();
+/// assert_eq!(
+///     expr,
+///     Expr::BinaryOp(
+///         BinaryOp::Add,
+///         Box::new(Expr::Const(Token::Number(Number::new(false, 1, 0)))),
+///         Box::new(Expr::BinaryOp(
+///             BinaryOp::Mul,
+///             Box::new(Expr::Const(Token::Number(Number::new(false, 2, 0)))),
+///             Box::new(Expr::Const(Token::Number(Number::new(false, 3, 0)))),
+///         )),
+///     )
+/// );
+/// ```
+pub fn parse_expr(input: &str) -> nom::IResult<&str, Expr> {
+    parse_binary_expr(input, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Number;
+
+    fn num(n: i64) -> Expr {
+        Expr::Const(Token::Number(Number::new(n < 0, n.abs(), 0)))
+    }
+
+    #[test]
+    fn test_parse_binary_left_associative() {
+        // 1 - 2 - 3 == (1 - 2) - 3
+        assert_eq!(
+            parse_expr("1 - 2 - 3").unwrap().1,
+            Expr::BinaryOp(
+                BinaryOp::Sub,
+                Box::new(Expr::BinaryOp(BinaryOp::Sub, Box::new(num(1)), Box::new(num(2)))),
+                Box::new(num(3)),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_binary_right_associative() {
+        // 2 ** 3 ** 2 == 2 ** (3 ** 2)
+        assert_eq!(
+            parse_expr("2 ** 3 ** 2").unwrap().1,
+            Expr::BinaryOp(
+                BinaryOp::Pow,
+                Box::new(num(2)),
+                Box::new(Expr::BinaryOp(BinaryOp::Pow, Box::new(num(3)), Box::new(num(2)))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_binary_precedence() {
+        // 1 + 2 * 3 == 1 + (2 * 3)
+        assert_eq!(
+            parse_expr("1 + 2 * 3").unwrap().1,
+            Expr::BinaryOp(
+                BinaryOp::Add,
+                Box::new(num(1)),
+                Box::new(Expr::BinaryOp(BinaryOp::Mul, Box::new(num(2)), Box::new(num(3)))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_binary_comparison_and_logic() {
+        // 1 < 2 && 3 == 3
+        assert_eq!(
+            parse_expr("1 < 2 && 3 == 3").unwrap().1,
+            Expr::BinaryOp(
+                BinaryOp::And,
+                Box::new(Expr::BinaryOp(BinaryOp::Lt, Box::new(num(1)), Box::new(num(2)))),
+                Box::new(Expr::BinaryOp(BinaryOp::Eq, Box::new(num(3)), Box::new(num(3)))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_binary_and_binds_tighter_than_or() {
+        // 1 == 1 || 0 == 1 && 0 == 1 == (1 == 1) || (0 == 1 && 0 == 1)
+        assert_eq!(
+            parse_expr("1 == 1 || 0 == 1 && 0 == 1").unwrap().1,
+            Expr::BinaryOp(
+                BinaryOp::Or,
+                Box::new(Expr::BinaryOp(BinaryOp::Eq, Box::new(num(1)), Box::new(num(1)))),
+                Box::new(Expr::BinaryOp(
+                    BinaryOp::And,
+                    Box::new(Expr::BinaryOp(BinaryOp::Eq, Box::new(num(0)), Box::new(num(1)))),
+                    Box::new(Expr::BinaryOp(BinaryOp::Eq, Box::new(num(0)), Box::new(num(1)))),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_unary() {
+        assert_eq!(
+            parse_expr("-1 + 2").unwrap().1,
+            Expr::BinaryOp(
+                BinaryOp::Add,
+                Box::new(Expr::UnaryOp(UnaryOp::Neg, Box::new(num(1)))),
+                Box::new(num(2)),
+            )
+        );
+        assert_eq!(
+            parse_expr("!1").unwrap().1,
+            Expr::UnaryOp(UnaryOp::Not, Box::new(num(1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_string_literal() {
+        assert_eq!(
+            parse_expr("\"hi\"").unwrap().1,
+            Expr::Const(Token::String("hi".into()))
+        );
+    }
+}
@@ -2,23 +2,186 @@
 
 #![deny(missing_docs)]
 
+use std::fmt::{self, Write};
+
 use nom::{
     branch::alt,
-    bytes::complete::{escaped_transform, tag},
-    character::complete::{char, none_of, one_of},
-    combinator::{opt, recognize},
-    error::context,
-    multi::{many0, many1},
-    sequence::{delimited, preceded, terminated, tuple},
+    bytes::complete::{escaped_transform, tag, take_while_m_n},
+    character::complete::{char, multispace0, none_of, one_of},
+    combinator::{cut, map, map_res, opt, recognize, value},
+    error::{context, ErrorKind, ParseError},
+    multi::{many0, many1, separated_list0},
+    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    Err,
 };
 
+mod expr;
+pub use expr::*;
+
 /// The type of token returned from the tokenizer.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     /// A single/double quoted string delimited value.
     String(String),
-    /// A floating point number.
-    Number(f64),
+    /// A decimal number kept as sign, mantissa and exponent; see [`Number`].
+    Number(Number),
+    /// The literal `null`.
+    Null,
+    /// The literal `true` or `false`.
+    Bool(bool),
+    /// An ordered list of values, e.g. `[1, 2, 3]`.
+    Array(Vec<Token>),
+    /// A key/value mapping, e.g. `{"a": 1}`. Keys preserve insertion order.
+    Object(Vec<(String, Token)>),
+    /// A lexeme [`lex`] could not make sense of. Carries the [`LexErrorKind`] so a
+    /// caller can report what went wrong without the lexer having to abort.
+    Error(LexErrorKind),
+}
+
+/// Why [`lex`] emitted a [`Token::Error`] for a lexeme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+    /// No known token starts at this position.
+    Unrecognized,
+}
+
+impl Token {
+    /// Serialize this token back to canonical JSON text.
+    ///
+    /// ## Example:
+    /// ```
+    /// # use zjson::*;
+    /// let (_, token) = parse_value("{\"a\": [1, \"b\\nc\"]}").unwrap();
+    /// assert_eq!(token.to_json(), "{\"a\":[1,\"b\\nc\"]}");
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out)
+            .expect("writing JSON to a String can't fail");
+        out
+    }
+
+    /// Write canonical JSON text for this token to `out`.
+    pub fn write_json(&self, out: &mut impl Write) -> fmt::Result {
+        match self {
+            Token::Null => out.write_str("null"),
+            Token::Bool(b) => out.write_str(if *b { "true" } else { "false" }),
+            Token::Number(number) => out.write_str(&number.to_json()),
+            Token::String(s) => write_json_string(s, out),
+            Token::Array(items) => {
+                out.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.write_char(',')?;
+                    }
+                    item.write_json(out)?;
+                }
+                out.write_char(']')
+            }
+            Token::Object(entries) => {
+                out.write_char('{')?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.write_char(',')?;
+                    }
+                    write_json_string(key, out)?;
+                    out.write_char(':')?;
+                    value.write_json(out)?;
+                }
+                out.write_char('}')
+            }
+            // Error tokens only ever come out of `lex`, never `parse_value`, so there's
+            // no meaningful JSON to produce; `null` is the closest valid stand-in.
+            Token::Error(_) => out.write_str("null"),
+        }
+    }
+}
+
+/// Write `s` as a double quoted, fully escaped JSON string literal.
+fn write_json_string(s: &str, out: &mut impl Write) -> fmt::Result {
+    out.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\t' => out.write_str("\\t")?,
+            '\r' => out.write_str("\\r")?,
+            '\u{08}' => out.write_str("\\b")?,
+            '\u{0C}' => out.write_str("\\f")?,
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+/// Run `inner`, consuming any surrounding whitespace.
+pub(crate) fn ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> nom::IResult<&'a str, O>
+where
+    F: FnMut(&'a str) -> nom::IResult<&'a str, O>,
+{
+    delimited(multispace0, inner, multispace0)
+}
+
+/// Parse exactly four hex digits into their numeric value, as used by `\uXXXX` escapes.
+fn parse_hex4(input: &str) -> nom::IResult<&str, u32> {
+    map_res(
+        take_while_m_n(4, 4, |c: char| c.is_ascii_hexdigit()),
+        |digits| u32::from_str_radix(digits, 16),
+    )(input)
+}
+
+/// Parse a `\uXXXX` escape, combining UTF-16 surrogate pairs into a single `char`.
+///
+/// An unpaired high or low surrogate is a hard parse failure rather than something
+/// `alt` should backtrack past, since no other branch could make sense of it.
+fn parse_unicode_escape(input: &str) -> nom::IResult<&str, char> {
+    let (input, _) = char('u')(input)?;
+    let (input, hi) = parse_hex4(input)?;
+
+    if (0xD800..=0xDBFF).contains(&hi) {
+        let (input, lo) = cut(preceded(tag("\\u"), parse_hex4))(input)?;
+        if !(0xDC00..=0xDFFF).contains(&lo) {
+            return Err(Err::Failure(nom::error::Error::from_error_kind(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+        let codepoint = 0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00);
+        let c = char::from_u32(codepoint).ok_or_else(|| {
+            Err::Failure(nom::error::Error::from_error_kind(input, ErrorKind::Verify))
+        })?;
+        Ok((input, c))
+    } else if (0xDC00..=0xDFFF).contains(&hi) {
+        Err(Err::Failure(nom::error::Error::from_error_kind(
+            input,
+            ErrorKind::Verify,
+        )))
+    } else {
+        let c = char::from_u32(hi).ok_or_else(|| {
+            Err::Failure(nom::error::Error::from_error_kind(input, ErrorKind::Verify))
+        })?;
+        Ok((input, c))
+    }
+}
+
+/// Build the escape-sequence transform for a string delimited by `quote`, dispatching
+/// the character after a backslash to its decoded replacement.
+fn parse_escape(quote: char) -> impl FnMut(&str) -> nom::IResult<&str, char> {
+    move |input| {
+        alt((
+            value('\n', char('n')),
+            value('\t', char('t')),
+            value('\r', char('r')),
+            value('\u{08}', char('b')),
+            value('\u{0C}', char('f')),
+            value('/', char('/')),
+            value('\\', char('\\')),
+            value(quote, char(quote)),
+            parse_unicode_escape,
+        ))(input)
+    }
 }
 
 /// Parse a single quoted string.
@@ -34,11 +197,15 @@ pub fn parse_single_quoted_string(input: &str) -> nom::IResult<&str, Token> {
         "single_quoted_string",
         delimited(
             tag("'"),
-            escaped_transform(none_of("\\'"), '\\', alt((tag("\\"), tag("'")))),
+            // `escaped_transform` requires at least one match, so an empty string
+            // (`''`) would otherwise fail to parse at all.
+            map(opt(escaped_transform(none_of("\\'"), '\\', parse_escape('\''))), |s| {
+                s.unwrap_or_default()
+            }),
             tag("'"),
         ),
     )(input)
-    .map(|(next_input, res)| (next_input, Token::String(res.into())))
+    .map(|(next_input, res)| (next_input, Token::String(res)))
 }
 
 /// Parse a double quoted string.
@@ -54,41 +221,171 @@ pub fn parse_double_quoted_string(input: &str) -> nom::IResult<&str, Token> {
         "double_quoted_string",
         delimited(
             tag("\""),
-            escaped_transform(none_of("\\\""), '\\', alt((tag("\\"), tag("\"")))),
+            // `escaped_transform` requires at least one match, so an empty string
+            // (`""`) would otherwise fail to parse at all.
+            map(opt(escaped_transform(none_of("\\\""), '\\', parse_escape('"'))), |s| {
+                s.unwrap_or_default()
+            }),
             tag("\""),
         ),
     )(input)
     .map(|(next_input, res)| (next_input, Token::String(res)))
 }
 
+/// A JSON number, stored as `sign * mantissa * 10^exponent` with an `i64` mantissa and
+/// an `i64` exponent.
+///
+/// Plain `f64` loses precision on huge mantissas and overflows/underflows on extreme
+/// exponents (e.g. `1e999999999999999999`). Keeping the decimal digits and exponent
+/// apart lets [`parse_float`] accept any spec-legal number without panicking, at the
+/// cost of only being able to *use* the value numerically via the lossy [`Number::as_f64`].
+/// A mantissa or exponent with more digits than an `i64` holds saturates at the `i64`
+/// bounds, so such a value is accepted but not kept exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Number {
+    /// Whether the number is negative.
+    pub negative: bool,
+    /// The decimal digits of the number, with the decimal point removed, saturating at
+    /// `i64::MAX`.
+    pub mantissa: i64,
+    /// The power of ten `mantissa` is scaled by.
+    pub exponent: i64,
+}
+
+impl Number {
+    /// Build a number from its sign, mantissa, and exponent.
+    pub fn new(negative: bool, mantissa: i64, exponent: i64) -> Self {
+        Self {
+            negative,
+            mantissa,
+            exponent,
+        }
+    }
+
+    /// Lossily convert to an `f64`. The exponent is clamped to the `i32` range that
+    /// `powi` takes rather than wrapping, so a value too large for `f64` comes out as
+    /// infinity; a zero mantissa always gives zero.
+    pub fn as_f64(&self) -> f64 {
+        if self.mantissa == 0 {
+            return if self.negative { -0.0 } else { 0.0 };
+        }
+        let exponent = self.exponent.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        let magnitude = self.mantissa as f64 * 10f64.powi(exponent);
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Serialize back to exact JSON number text (no `f64` rounding involved): plain
+    /// digits for a zero exponent, scientific notation for a positive exponent, and
+    /// fixed-point notation for a negative exponent unless that would need more than
+    /// 32 zeros of padding, in which case scientific notation again.
+    ///
+    /// `-e` itself can overflow `i64` (`e == i64::MIN`), and even when it doesn't it
+    /// can be far larger than `digits.len()`; either way zero-padding out to it would
+    /// try to build a multi-exabyte string. Widen to `i128` to negate safely, and fall
+    /// back to scientific notation once the padded width stops being sane.
+    fn to_json(self) -> String {
+        let sign = if self.negative { "-" } else { "" };
+        let digits = self.mantissa.to_string();
+        match self.exponent {
+            0 => format!("{sign}{digits}"),
+            e if e > 0 => format!("{sign}{digits}e{e}"),
+            e => {
+                let width = -(e as i128);
+                if width < digits.len() as i128 {
+                    let split = digits.len() - width as usize;
+                    let (int_part, frac_part) = digits.split_at(split);
+                    format!("{sign}{int_part}.{frac_part}")
+                } else if width <= digits.len() as i128 + 32 {
+                    format!("{sign}0.{digits:0>w$}", w = width as usize)
+                } else {
+                    format!("{sign}{digits}e{e}")
+                }
+            }
+        }
+    }
+}
+
+/// Fold a run of decimal digits (optionally interspersed with `_` separators) into
+/// `acc`, saturating instead of overflowing.
+fn fold_digits(acc: i64, digits: &str) -> i64 {
+    digits
+        .chars()
+        .filter(|c| *c != '_')
+        .fold(acc, |acc, c| {
+            acc.saturating_mul(10)
+                .saturating_add(c.to_digit(10).unwrap() as i64)
+        })
+}
+
+/// Parse the `e`/`E` exponent suffix of a number into a signed `i64`, saturating
+/// rather than overflowing on absurdly long digit runs.
+fn parse_exponent(input: &str) -> nom::IResult<&str, i64> {
+    preceded(
+        one_of("eE"),
+        map(tuple((opt(one_of("+-")), parse_decimal)), |(sign, digits)| {
+            let magnitude = fold_digits(0, digits);
+            if sign == Some('-') {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }),
+    )(input)
+}
+
 /// Parse a floating point number.
 ///
-/// Borrowed from [Nom Recipes](https://docs.rs/nom/latest/nom/recipes/index.html#floating-point-numbers).
+/// Builds a [`Number`] straight from the matched digit runs instead of re-parsing the
+/// recognized slice as an `f64`, so a mantissa or exponent too long for an `i64`
+/// saturates at the `i64` bounds instead of panicking.
 ///
 /// ## Example:
 /// ```
 /// # use zjson::*;
 /// let (_, number) = parse_float("13.37").unwrap();
-/// assert_eq!(number, Token::Number(13.37));;
+/// assert_eq!(number, Token::Number(Number::new(false, 1337, -2)));
 /// ```
 pub fn parse_float(input: &str) -> nom::IResult<&str, Token> {
-    alt((
-        // Case one: .42
-        recognize(tuple((
-            char('.'),
-            parse_decimal,
-            opt(tuple((one_of("eE"), opt(one_of("+-")), parse_decimal))),
-        ))), // Case two: 42e42 and 42.42e42
-        recognize(tuple((
-            parse_decimal,
-            opt(preceded(char('.'), parse_decimal)),
-            one_of("eE"),
-            opt(one_of("+-")),
-            parse_decimal,
-        ))), // Case three: 42. and 42.42
-        recognize(tuple((parse_decimal, char('.'), opt(parse_decimal)))),
-    ))(input)
-    .map(|(next_input, res)| (next_input, Token::Number(res.parse::<f64>().unwrap())))
+    let (input, negative) = map(opt(char('-')), |sign| sign.is_some())(input)?;
+    let (input, (int_part, frac_part, exp)) = alt((
+        // Case one: .42 and .42e42
+        map(
+            tuple((char('.'), parse_decimal, opt(parse_exponent))),
+            |(_, frac, exp)| (None, Some(frac), exp),
+        ),
+        // Case two: 42, 42.42, 42., 42e42, and 42.42e42
+        map(
+            tuple((
+                parse_decimal,
+                opt(preceded(char('.'), opt(parse_decimal))),
+                opt(parse_exponent),
+            )),
+            |(int_part, frac, exp)| (Some(int_part), frac.flatten(), exp),
+        ),
+    ))(input)?;
+
+    let mut mantissa = 0i64;
+    let mut exponent = 0i64;
+    if let Some(digits) = int_part {
+        mantissa = fold_digits(mantissa, digits);
+    }
+    if let Some(digits) = frac_part {
+        mantissa = fold_digits(mantissa, digits);
+        let frac_len = digits.chars().filter(|c| *c != '_').count() as i64;
+        exponent = exponent.saturating_sub(frac_len);
+    }
+    if let Some(e) = exp {
+        exponent = exponent.saturating_add(e);
+    }
+
+    Ok((
+        input,
+        Token::Number(Number::new(negative, mantissa, exponent)),
+    ))
 }
 
 fn parse_decimal(input: &str) -> nom::IResult<&str, &str> {
@@ -113,6 +410,188 @@ pub fn parse_string(input: &str) -> nom::IResult<&str, Token> {
     )(input)
 }
 
+/// Parse the literal `null`.
+///
+/// ## Example:
+/// ```
+/// # use zjson::*;
+/// let (_, token) = parse_null("null").unwrap();
+/// assert_eq!(token, Token::Null);
+/// ```
+pub fn parse_null(input: &str) -> nom::IResult<&str, Token> {
+    context("null", value(Token::Null, tag("null")))(input)
+}
+
+/// Parse the literals `true` and `false`.
+///
+/// ## Example:
+/// ```
+/// # use zjson::*;
+/// let (_, token) = parse_bool("true").unwrap();
+/// assert_eq!(token, Token::Bool(true));
+/// ```
+pub fn parse_bool(input: &str) -> nom::IResult<&str, Token> {
+    context(
+        "bool",
+        alt((
+            value(Token::Bool(true), tag("true")),
+            value(Token::Bool(false), tag("false")),
+        )),
+    )(input)
+}
+
+/// Parse a `[` delimited, comma separated list of values.
+///
+/// ## Example:
+/// ```
+/// # use zjson::*;
+/// let (_, token) = parse_array("[1, 2.5, \"three\"]").unwrap();
+/// assert_eq!(
+///     token,
+///     Token::Array(vec![
+///         Token::Number(Number::new(false, 1, 0)),
+///         Token::Number(Number::new(false, 25, -1)),
+///         Token::String("three".into()),
+///     ])
+/// );
+/// ```
+pub fn parse_array(input: &str) -> nom::IResult<&str, Token> {
+    context(
+        "array",
+        delimited(
+            char('['),
+            ws(separated_list0(ws(char(',')), ws(parse_value))),
+            char(']'),
+        ),
+    )(input)
+    .map(|(next_input, res)| (next_input, Token::Array(res)))
+}
+
+fn parse_object_key(input: &str) -> nom::IResult<&str, String> {
+    parse_string(input).map(|(next_input, token)| match token {
+        Token::String(key) => (next_input, key),
+        _ => unreachable!("parse_string only ever produces Token::String"),
+    })
+}
+
+/// Parse a `{` delimited, comma separated list of `"key": value` pairs.
+///
+/// ## Example:
+/// ```
+/// # use zjson::*;
+/// let (_, token) = parse_object("{\"a\": 1, \"b\": true}").unwrap();
+/// assert_eq!(
+///     token,
+///     Token::Object(vec![
+///         ("a".into(), Token::Number(Number::new(false, 1, 0))),
+///         ("b".into(), Token::Bool(true)),
+///     ])
+/// );
+/// ```
+pub fn parse_object(input: &str) -> nom::IResult<&str, Token> {
+    context(
+        "object",
+        delimited(
+            char('{'),
+            ws(separated_list0(
+                ws(char(',')),
+                separated_pair(ws(parse_object_key), ws(char(':')), parse_value),
+            )),
+            char('}'),
+        ),
+    )(input)
+    .map(|(next_input, res)| (next_input, Token::Object(res)))
+}
+
+/// Parse a complete JSON value: an object, array, bool, null, string, or number.
+///
+/// ## Example:
+/// ```
+/// # use zjson::*;
+/// let (_, token) = parse_value("[null, true, \"hi\"]").unwrap();
+/// assert_eq!(
+///     token,
+///     Token::Array(vec![Token::Null, Token::Bool(true), Token::String("hi".into())])
+/// );
+/// ```
+pub fn parse_value(input: &str) -> nom::IResult<&str, Token> {
+    context(
+        "value",
+        alt((
+            parse_object,
+            parse_array,
+            parse_bool,
+            parse_null,
+            parse_string,
+            parse_float,
+        )),
+    )(input)
+}
+
+/// A [`Token`] together with the byte range of the input it was lexed from.
+#[derive(Debug, PartialEq)]
+pub struct Spanned {
+    /// The token that was lexed.
+    pub token: Token,
+    /// The byte offset, from the start of the input, the token begins at.
+    pub start: usize,
+    /// The number of bytes the token spans.
+    pub len: usize,
+}
+
+/// Tokenize an entire input into a flat stream of [`Spanned`] tokens, never failing.
+///
+/// Insignificant whitespace between tokens is skipped rather than recorded. Unlike
+/// [`parse_value`], this never returns an error: a lexeme it can't recognize becomes a
+/// [`Token::Error`] spanning up to the next whitespace boundary, so one bad lexeme
+/// doesn't stop the rest of the input from being tokenized. Callers that want
+/// line/column diagnostics can map `start`/`len` back onto the original input.
+pub fn lex(input: &str) -> Vec<Spanned> {
+    let mut spanned = Vec::new();
+    let mut offset = 0;
+    let mut remaining = input;
+
+    loop {
+        let ws: nom::IResult<&str, &str> = multispace0(remaining);
+        let (next, skipped) = ws.unwrap();
+        offset += skipped.len();
+        remaining = next;
+
+        if remaining.is_empty() {
+            break;
+        }
+
+        match parse_value(remaining) {
+            Ok((next, token)) => {
+                let len = remaining.len() - next.len();
+                spanned.push(Spanned {
+                    token,
+                    start: offset,
+                    len,
+                });
+                offset += len;
+                remaining = next;
+            }
+            Err(_) => {
+                let len = remaining
+                    .char_indices()
+                    .skip(1)
+                    .find(|&(_, c)| c.is_whitespace())
+                    .map_or(remaining.len(), |(idx, _)| idx);
+                spanned.push(Spanned {
+                    token: Token::Error(LexErrorKind::Unrecognized),
+                    start: offset,
+                    len,
+                });
+                offset += len;
+                remaining = &remaining[len..];
+            }
+        }
+    }
+
+    spanned
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,6 +602,10 @@ mod tests {
             parse_single_quoted_string("'hello'").unwrap().1,
             Token::String("hello".into())
         );
+        assert_eq!(
+            parse_single_quoted_string("''").unwrap().1,
+            Token::String("".into())
+        );
         assert!(parse_single_quoted_string("'yoted").is_err());
         assert!(parse_single_quoted_string("yoted'").is_err());
         assert!(parse_single_quoted_string("yoted").is_err());
@@ -134,6 +617,10 @@ mod tests {
             parse_double_quoted_string("\"hello\"").unwrap().1,
             Token::String("hello".into())
         );
+        assert_eq!(
+            parse_double_quoted_string("\"\"").unwrap().1,
+            Token::String("".into())
+        );
         assert!(parse_double_quoted_string("\"yoted").is_err());
         assert!(parse_double_quoted_string("yoted\"").is_err());
         assert!(parse_double_quoted_string("yoted").is_err());
@@ -158,6 +645,39 @@ mod tests {
         assert!(parse_string("yoted").is_err());
     }
 
+    #[test]
+    fn test_parse_string_escapes() {
+        assert_eq!(
+            parse_string("\"line\\nbreak\"").unwrap().1,
+            Token::String("line\nbreak".into())
+        );
+        assert_eq!(
+            parse_string("\"a\\tb\\rc\\bd\\fe\\/f\"").unwrap().1,
+            Token::String("a\tb\rc\u{08}d\u{0C}e/f".into())
+        );
+        assert_eq!(
+            parse_string("'it\\'s'").unwrap().1,
+            Token::String("it's".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_string_unicode_escapes() {
+        assert_eq!(
+            parse_string("\"\\u00e9\"").unwrap().1,
+            Token::String("é".into())
+        );
+        // Surrogate pair for U+1F600 (😀).
+        assert_eq!(
+            parse_string("\"\\ud83d\\ude00\"").unwrap().1,
+            Token::String("😀".into())
+        );
+        // An unpaired high surrogate must fail to parse.
+        assert!(parse_string("\"\\ud83d\"").is_err());
+        // A lone low surrogate must fail to parse.
+        assert!(parse_string("\"\\ude00\"").is_err());
+    }
+
     #[test]
     fn test_parse_decimal() {
         assert_eq!(parse_decimal("3000").unwrap().1, "3000");
@@ -167,8 +687,211 @@ mod tests {
 
     #[test]
     fn test_parse_float() {
-        assert_eq!(parse_float("13.37").unwrap().1, Token::Number(13.37));
-        assert_eq!(parse_float(".37").unwrap().1, Token::Number(0.37));
-        assert_eq!(parse_float("10e4").unwrap().1, Token::Number(10.0e4));
+        assert_eq!(
+            parse_float("13.37").unwrap().1,
+            Token::Number(Number::new(false, 1337, -2))
+        );
+        assert_eq!(
+            parse_float(".37").unwrap().1,
+            Token::Number(Number::new(false, 37, -2))
+        );
+        assert_eq!(
+            parse_float("10e4").unwrap().1,
+            Token::Number(Number::new(false, 10, 4))
+        );
+        assert_eq!(
+            parse_float("42").unwrap().1,
+            Token::Number(Number::new(false, 42, 0))
+        );
+        assert_eq!(
+            parse_float("-5").unwrap().1,
+            Token::Number(Number::new(true, 5, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_float_huge_exponent_does_not_panic() {
+        let (_, token) = parse_float("1e999999999999999999999999999999").unwrap();
+        assert_eq!(token, Token::Number(Number::new(false, 1, i64::MAX)));
+        let Token::Number(number) = token else {
+            unreachable!()
+        };
+        assert_eq!(number.as_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_parse_null() {
+        assert_eq!(parse_null("null").unwrap().1, Token::Null);
+        assert!(parse_null("nul").is_err());
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        assert_eq!(parse_bool("true").unwrap().1, Token::Bool(true));
+        assert_eq!(parse_bool("false").unwrap().1, Token::Bool(false));
+        assert!(parse_bool("trueish").is_ok());
+        assert!(parse_bool("tru").is_err());
+    }
+
+    #[test]
+    fn test_parse_array() {
+        assert_eq!(parse_array("[]").unwrap().1, Token::Array(vec![]));
+        assert_eq!(
+            parse_array("[1, 2, 3]").unwrap().1,
+            Token::Array(vec![
+                Token::Number(Number::new(false, 1, 0)),
+                Token::Number(Number::new(false, 2, 0)),
+                Token::Number(Number::new(false, 3, 0)),
+            ])
+        );
+        assert_eq!(
+            parse_array("[ 1 , [ 2 ] ]").unwrap().1,
+            Token::Array(vec![
+                Token::Number(Number::new(false, 1, 0)),
+                Token::Array(vec![Token::Number(Number::new(false, 2, 0))])
+            ])
+        );
+        assert!(parse_array("[1, 2,").is_err());
+    }
+
+    #[test]
+    fn test_parse_object() {
+        assert_eq!(parse_object("{}").unwrap().1, Token::Object(vec![]));
+        assert_eq!(
+            parse_object("{\"a\": 1, \"b\": null}").unwrap().1,
+            Token::Object(vec![
+                ("a".into(), Token::Number(Number::new(false, 1, 0))),
+                ("b".into(), Token::Null),
+            ])
+        );
+        assert!(parse_object("{a: 1}").is_err());
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("null").unwrap().1, Token::Null);
+        assert_eq!(parse_value("false").unwrap().1, Token::Bool(false));
+        assert_eq!(
+            parse_value("42.5").unwrap().1,
+            Token::Number(Number::new(false, 425, -1))
+        );
+        assert_eq!(
+            parse_value("\"hi\"").unwrap().1,
+            Token::String("hi".into())
+        );
+        assert_eq!(
+            parse_value("{\"nested\": [1, true, null]}").unwrap().1,
+            Token::Object(vec![(
+                "nested".into(),
+                Token::Array(vec![
+                    Token::Number(Number::new(false, 1, 0)),
+                    Token::Bool(true),
+                    Token::Null
+                ])
+            )])
+        );
+    }
+
+    #[test]
+    fn test_lex() {
+        let spanned = lex("  true null");
+        assert_eq!(
+            spanned,
+            vec![
+                Spanned {
+                    token: Token::Bool(true),
+                    start: 2,
+                    len: 4,
+                },
+                Spanned {
+                    token: Token::Null,
+                    start: 7,
+                    len: 4,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_empty() {
+        assert_eq!(lex(""), vec![]);
+        assert_eq!(lex("   "), vec![]);
+    }
+
+    #[test]
+    fn test_lex_recovers_from_errors() {
+        let spanned = lex("true @@@ false");
+        assert_eq!(
+            spanned,
+            vec![
+                Spanned {
+                    token: Token::Bool(true),
+                    start: 0,
+                    len: 4,
+                },
+                Spanned {
+                    token: Token::Error(LexErrorKind::Unrecognized),
+                    start: 5,
+                    len: 3,
+                },
+                Spanned {
+                    token: Token::Bool(false),
+                    start: 9,
+                    len: 5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(Token::Null.to_json(), "null");
+        assert_eq!(Token::Bool(true).to_json(), "true");
+        assert_eq!(
+            Token::Number(Number::new(false, 1337, -2)).to_json(),
+            "13.37"
+        );
+        assert_eq!(Token::Number(Number::new(true, 5, 0)).to_json(), "-5");
+        assert_eq!(Token::Number(Number::new(false, 37, -5)).to_json(), "0.00037");
+        assert_eq!(Token::Number(Number::new(false, 10, 4)).to_json(), "10e4");
+        assert_eq!(
+            Token::String("line\\break \"quoted\"".into()).to_json(),
+            "\"line\\\\break \\\"quoted\\\"\""
+        );
+        assert_eq!(
+            Token::Array(vec![Token::Number(Number::new(false, 1, 0)), Token::Null]).to_json(),
+            "[1,null]"
+        );
+        assert_eq!(
+            Token::Object(vec![("a".into(), Token::Bool(false))]).to_json(),
+            "{\"a\":false}"
+        );
+    }
+
+    #[test]
+    fn test_parse_serialize_roundtrip() {
+        let inputs = [
+            "null",
+            "true",
+            "false",
+            "0",
+            "-5",
+            "13.37",
+            "10e4",
+            "0.00037",
+            "1e-9999999999999999999",
+            "\"\"",
+            "\"hello\\nworld\"",
+            "\"\\u00e9\\ud83d\\ude00\"",
+            "[1, 2.5, \"three\", null, true, [false]]",
+            "{\"a\": 1, \"b\": [2, {\"c\": null}]}",
+        ];
+
+        for input in inputs {
+            let (_, first) = parse_value(input).unwrap();
+            let serialized = first.to_json();
+            let (_, second) = parse_value(&serialized).unwrap();
+            assert_eq!(first, second, "round trip mismatch for {input}");
+        }
     }
 }